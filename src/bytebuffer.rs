@@ -1,12 +1,32 @@
 use std::collections::HashMap;
-use std::io::{Cursor, Read, Write};
+use std::io::{Cursor, Read, SeekFrom, Write};
 use std::str;
-use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt, WriteBytesExt};
+use flate2::read::{DeflateDecoder, DeflateEncoder, ZlibDecoder, ZlibEncoder};
+use flate2::Compression;
+
+/// Byte order used when reading and writing multi-byte numeric values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Endian {
+    #[default]
+    Little,
+    Big,
+}
+
+/// Compression scheme used by [`ByteBuffer::compress`] and
+/// [`ByteBuffer::uncompress`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompressionAlgorithm {
+    Zlib,
+    Deflate,
+    Lzma,
+}
 
 #[derive(Clone)]
 pub struct ByteBuffer {
     buffer: Vec<u8>,
     position: usize,
+    endian: Endian,
 }
 
 impl ByteBuffer {
@@ -14,9 +34,18 @@ impl ByteBuffer {
         ByteBuffer {
             buffer: data.unwrap_or_default(),
             position: 0,
+            endian: Endian::default(),
         }
     }
 
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    pub fn set_endian(&mut self, endian: Endian) {
+        self.endian = endian;
+    }
+
     pub fn ensure_capacity(&mut self, required_bytes: usize) {
         let required_capacity = self.position + required_bytes;
 
@@ -28,7 +57,10 @@ impl ByteBuffer {
     pub fn put_int32(&mut self, value: i32) -> &mut Self {
         self.ensure_capacity(4);
         let mut cursor = Cursor::new(&mut self.buffer[self.position..self.position + 4]);
-        cursor.write_i32::<LittleEndian>(value).unwrap();
+        match self.endian {
+            Endian::Little => cursor.write_i32::<LittleEndian>(value).unwrap(),
+            Endian::Big => cursor.write_i32::<BigEndian>(value).unwrap(),
+        }
         self.position += 4;
         self
     }
@@ -39,13 +71,19 @@ impl ByteBuffer {
         }
         let mut cursor = Cursor::new(&self.buffer[self.position..self.position + 4]);
         self.position += 4;
-        Ok(cursor.read_i32::<LittleEndian>().unwrap())
+        match self.endian {
+            Endian::Little => Ok(cursor.read_i32::<LittleEndian>().unwrap()),
+            Endian::Big => Ok(cursor.read_i32::<BigEndian>().unwrap()),
+        }
     }
 
     pub fn put_uint32(&mut self, value: u32) -> &mut Self {
         self.ensure_capacity(4);
         let mut cursor = Cursor::new(&mut self.buffer[self.position..self.position + 4]);
-        cursor.write_u32::<LittleEndian>(value).unwrap();
+        match self.endian {
+            Endian::Little => cursor.write_u32::<LittleEndian>(value).unwrap(),
+            Endian::Big => cursor.write_u32::<BigEndian>(value).unwrap(),
+        }
         self.position += 4;
         self
     }
@@ -56,7 +94,183 @@ impl ByteBuffer {
         }
         let mut cursor = Cursor::new(&self.buffer[self.position..self.position + 4]);
         self.position += 4;
-        Ok(cursor.read_u32::<LittleEndian>().unwrap())
+        match self.endian {
+            Endian::Little => Ok(cursor.read_u32::<LittleEndian>().unwrap()),
+            Endian::Big => Ok(cursor.read_u32::<BigEndian>().unwrap()),
+        }
+    }
+
+    pub fn put_i8(&mut self, value: i8) -> &mut Self {
+        self.ensure_capacity(1);
+        self.buffer[self.position] = value as u8;
+        self.position += 1;
+        self
+    }
+
+    pub fn get_i8(&mut self) -> Result<i8, String> {
+        Ok(self.get_byte()? as i8)
+    }
+
+    pub fn put_u16(&mut self, value: u16) -> &mut Self {
+        self.ensure_capacity(2);
+        let mut cursor = Cursor::new(&mut self.buffer[self.position..self.position + 2]);
+        match self.endian {
+            Endian::Little => cursor.write_u16::<LittleEndian>(value).unwrap(),
+            Endian::Big => cursor.write_u16::<BigEndian>(value).unwrap(),
+        }
+        self.position += 2;
+        self
+    }
+
+    pub fn get_u16(&mut self) -> Result<u16, String> {
+        if self.position + 2 > self.buffer.len() {
+            return Err("Buffer underflow".to_string());
+        }
+        let mut cursor = Cursor::new(&self.buffer[self.position..self.position + 2]);
+        self.position += 2;
+        match self.endian {
+            Endian::Little => Ok(cursor.read_u16::<LittleEndian>().unwrap()),
+            Endian::Big => Ok(cursor.read_u16::<BigEndian>().unwrap()),
+        }
+    }
+
+    pub fn put_i16(&mut self, value: i16) -> &mut Self {
+        self.ensure_capacity(2);
+        let mut cursor = Cursor::new(&mut self.buffer[self.position..self.position + 2]);
+        match self.endian {
+            Endian::Little => cursor.write_i16::<LittleEndian>(value).unwrap(),
+            Endian::Big => cursor.write_i16::<BigEndian>(value).unwrap(),
+        }
+        self.position += 2;
+        self
+    }
+
+    pub fn get_i16(&mut self) -> Result<i16, String> {
+        if self.position + 2 > self.buffer.len() {
+            return Err("Buffer underflow".to_string());
+        }
+        let mut cursor = Cursor::new(&self.buffer[self.position..self.position + 2]);
+        self.position += 2;
+        match self.endian {
+            Endian::Little => Ok(cursor.read_i16::<LittleEndian>().unwrap()),
+            Endian::Big => Ok(cursor.read_i16::<BigEndian>().unwrap()),
+        }
+    }
+
+    pub fn put_i64(&mut self, value: i64) -> &mut Self {
+        self.ensure_capacity(8);
+        let mut cursor = Cursor::new(&mut self.buffer[self.position..self.position + 8]);
+        match self.endian {
+            Endian::Little => cursor.write_i64::<LittleEndian>(value).unwrap(),
+            Endian::Big => cursor.write_i64::<BigEndian>(value).unwrap(),
+        }
+        self.position += 8;
+        self
+    }
+
+    pub fn get_i64(&mut self) -> Result<i64, String> {
+        if self.position + 8 > self.buffer.len() {
+            return Err("Buffer underflow".to_string());
+        }
+        let mut cursor = Cursor::new(&self.buffer[self.position..self.position + 8]);
+        self.position += 8;
+        match self.endian {
+            Endian::Little => Ok(cursor.read_i64::<LittleEndian>().unwrap()),
+            Endian::Big => Ok(cursor.read_i64::<BigEndian>().unwrap()),
+        }
+    }
+
+    pub fn put_u64(&mut self, value: u64) -> &mut Self {
+        self.ensure_capacity(8);
+        let mut cursor = Cursor::new(&mut self.buffer[self.position..self.position + 8]);
+        match self.endian {
+            Endian::Little => cursor.write_u64::<LittleEndian>(value).unwrap(),
+            Endian::Big => cursor.write_u64::<BigEndian>(value).unwrap(),
+        }
+        self.position += 8;
+        self
+    }
+
+    pub fn get_u64(&mut self) -> Result<u64, String> {
+        if self.position + 8 > self.buffer.len() {
+            return Err("Buffer underflow".to_string());
+        }
+        let mut cursor = Cursor::new(&self.buffer[self.position..self.position + 8]);
+        self.position += 8;
+        match self.endian {
+            Endian::Little => Ok(cursor.read_u64::<LittleEndian>().unwrap()),
+            Endian::Big => Ok(cursor.read_u64::<BigEndian>().unwrap()),
+        }
+    }
+
+    pub fn put_f64(&mut self, value: f64) -> &mut Self {
+        self.ensure_capacity(8);
+        let mut cursor = Cursor::new(&mut self.buffer[self.position..self.position + 8]);
+        match self.endian {
+            Endian::Little => cursor.write_f64::<LittleEndian>(value).unwrap(),
+            Endian::Big => cursor.write_f64::<BigEndian>(value).unwrap(),
+        }
+        self.position += 8;
+        self
+    }
+
+    pub fn get_f64(&mut self) -> Result<f64, String> {
+        if self.position + 8 > self.buffer.len() {
+            return Err("Buffer underflow".to_string());
+        }
+        let mut cursor = Cursor::new(&self.buffer[self.position..self.position + 8]);
+        self.position += 8;
+        match self.endian {
+            Endian::Little => Ok(cursor.read_f64::<LittleEndian>().unwrap()),
+            Endian::Big => Ok(cursor.read_f64::<BigEndian>().unwrap()),
+        }
+    }
+
+    /// Encodes `value` as an unsigned LEB128 varint: 7 bits per byte, low
+    /// bits first, with the high continuation bit set on every byte but
+    /// the last.
+    pub fn put_varint_u64(&mut self, value: u64) -> &mut Self {
+        let mut value = value;
+        loop {
+            let mut byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value != 0 {
+                byte |= 0x80;
+                self.put_byte(byte);
+            } else {
+                self.put_byte(byte);
+                break;
+            }
+        }
+        self
+    }
+
+    /// Decodes an unsigned LEB128 varint, rejecting streams that exceed 10
+    /// bytes (the maximum needed to represent a `u64`).
+    pub fn get_varint_u64(&mut self) -> Result<u64, String> {
+        let mut result: u64 = 0;
+        let mut shift = 0;
+        for _ in 0..10 {
+            let byte = self.get_byte()?;
+            result |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+            shift += 7;
+        }
+        Err("Varint overflow".to_string())
+    }
+
+    /// Zig-zag encodes `value` before writing it as an unsigned varint, so
+    /// small negative numbers stay compact.
+    pub fn put_varint_i64(&mut self, value: i64) -> &mut Self {
+        let zigzag = ((value << 1) ^ (value >> 63)) as u64;
+        self.put_varint_u64(zigzag)
+    }
+
+    pub fn get_varint_i64(&mut self) -> Result<i64, String> {
+        let zigzag = self.get_varint_u64()?;
+        Ok(((zigzag >> 1) as i64) ^ -((zigzag & 1) as i64))
     }
 
     pub fn put_byte(&mut self, value: u8) -> &mut Self {
@@ -75,6 +289,33 @@ impl ByteBuffer {
         Ok(value)
     }
 
+    /// Reads the next byte without advancing `position`.
+    pub fn peek_byte(&mut self) -> Result<u8, String> {
+        self.peek(|buffer| buffer.get_byte())
+    }
+
+    /// Reads the next `i32` without advancing `position`.
+    pub fn peek_int32(&mut self) -> Result<i32, String> {
+        self.peek(|buffer| buffer.get_int32())
+    }
+
+    /// Runs `f` against the buffer and restores `position` to what it was
+    /// beforehand, letting a caller look ahead without consuming bytes.
+    pub fn peek<T, F>(&mut self, f: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let position = self.position;
+        let result = f(self);
+        self.position = position;
+        result
+    }
+
+    /// Whether the cursor has reached the end of the buffer.
+    pub fn is_eof(&self) -> bool {
+        self.position >= self.buffer.len()
+    }
+
     pub fn put_bool(&mut self, value: bool) -> &mut Self {
         self.put_byte(if value { 1 } else { 0 })
     }
@@ -83,32 +324,51 @@ impl ByteBuffer {
         Ok(self.get_byte()? != 0)
     }
 
+    /// Writes `bytes` at the cursor in one copy, instead of writing byte by
+    /// byte, growing the buffer once if they run past its end.
+    pub fn put_bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        self.ensure_capacity(bytes.len());
+        self.buffer[self.position..self.position + bytes.len()].copy_from_slice(bytes);
+        self.position += bytes.len();
+        self
+    }
+
+    /// Reads and copies the next `len` bytes, advancing past them.
+    pub fn get_bytes(&mut self, len: usize) -> Result<Vec<u8>, String> {
+        Ok(self.get_slice(len)?.to_vec())
+    }
+
+    /// Borrows the next `len` bytes without copying, advancing past them.
+    pub fn get_slice(&mut self, len: usize) -> Result<&[u8], String> {
+        if len > self.buffer.len().saturating_sub(self.position) {
+            return Err("Buffer underflow".to_string());
+        }
+        let slice = &self.buffer[self.position..self.position + len];
+        self.position += len;
+        Ok(slice)
+    }
+
     pub fn put_string(&mut self, value: &str) -> &mut Self {
         let bytes = value.as_bytes();
         self.put_int32(bytes.len() as i32);
-        self.ensure_capacity(bytes.len());
-        for &byte in bytes {
-            self.put_byte(byte);
-        }
-        self
+        self.put_bytes(bytes)
     }
 
     pub fn get_string(&mut self) -> Result<String, String> {
         let length = self.get_int32()? as usize;
-        if self.position + length > self.buffer.len() {
-            return Err("Buffer underflow".to_string());
-        }
-        let value = str::from_utf8(&self.buffer[self.position..self.position + length])
+        let value = str::from_utf8(self.get_slice(length)?)
             .map_err(|_| "Invalid UTF-8 string".to_string())?
             .to_string();
-        self.position += length;
         Ok(value)
     }
 
     pub fn put_float(&mut self, value: f32) -> &mut Self {
         self.ensure_capacity(4);
         let mut cursor = Cursor::new(&mut self.buffer[self.position..self.position + 4]);
-        cursor.write_f32::<LittleEndian>(value).unwrap();
+        match self.endian {
+            Endian::Little => cursor.write_f32::<LittleEndian>(value).unwrap(),
+            Endian::Big => cursor.write_f32::<BigEndian>(value).unwrap(),
+        }
         self.position += 4;
         self
     }
@@ -119,7 +379,10 @@ impl ByteBuffer {
         }
         let mut cursor = Cursor::new(&self.buffer[self.position..self.position + 4]);
         self.position += 4;
-        Ok(cursor.read_f32::<LittleEndian>().unwrap())
+        match self.endian {
+            Endian::Little => Ok(cursor.read_f32::<LittleEndian>().unwrap()),
+            Endian::Big => Ok(cursor.read_f32::<BigEndian>().unwrap()),
+        }
     }
 
     pub fn put_vector(&mut self, vector: (f32, f32, f32)) -> &mut Self {
@@ -151,6 +414,113 @@ impl ByteBuffer {
     pub fn to_hex(&self) -> String {
         self.buffer.iter().map(|b| format!("{:02x}", b)).collect()
     }
+
+    /// Total number of bytes currently stored in the buffer.
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Current read/write cursor position.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Moves the cursor to `position`, rejecting offsets past the end of
+    /// the buffer.
+    pub fn set_position(&mut self, position: usize) -> Result<(), String> {
+        if position > self.buffer.len() {
+            return Err("Position out of bounds".to_string());
+        }
+        self.position = position;
+        Ok(())
+    }
+
+    pub fn reset_position(&mut self) {
+        self.position = 0;
+    }
+
+    /// Number of unread bytes between the cursor and the end of the
+    /// buffer.
+    pub fn remaining(&self) -> usize {
+        self.buffer.len() - self.position
+    }
+
+    /// Moves the cursor relative to the start, the current position, or
+    /// the end. As with `std::io::Cursor`'s `Seek`, offsets that would land
+    /// before the start are rejected; unlike it, offsets past the end are
+    /// clamped to the buffer's length.
+    pub fn seek(&mut self, from: SeekFrom) -> Result<usize, String> {
+        let (base, offset) = match from {
+            SeekFrom::Start(offset) => (0i128, offset as i128),
+            SeekFrom::Current(offset) => (self.position as i128, offset as i128),
+            SeekFrom::End(offset) => (self.buffer.len() as i128, offset as i128),
+        };
+        let new_position = base + offset;
+        if new_position < 0 {
+            return Err("Invalid seek to a negative position".to_string());
+        }
+        self.position = new_position.min(self.buffer.len() as i128) as usize;
+        Ok(self.position)
+    }
+
+    /// Replaces `buffer` with its compressed form and moves `position` to
+    /// the new end.
+    pub fn compress(&mut self, algorithm: CompressionAlgorithm) -> Result<(), String> {
+        let mut compressed = Vec::new();
+        match algorithm {
+            CompressionAlgorithm::Zlib => {
+                let mut encoder = ZlibEncoder::new(self.buffer.as_slice(), Compression::default());
+                encoder
+                    .read_to_end(&mut compressed)
+                    .map_err(|e| e.to_string())?;
+            }
+            CompressionAlgorithm::Deflate => {
+                let mut encoder =
+                    DeflateEncoder::new(self.buffer.as_slice(), Compression::default());
+                encoder
+                    .read_to_end(&mut compressed)
+                    .map_err(|e| e.to_string())?;
+            }
+            CompressionAlgorithm::Lzma => {
+                lzma_rs::lzma_compress(&mut self.buffer.as_slice(), &mut compressed)
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+        self.buffer = compressed;
+        self.position = self.buffer.len();
+        Ok(())
+    }
+
+    /// Decodes `buffer` from its compressed form, starting from the front,
+    /// and resets `position` to 0.
+    pub fn uncompress(&mut self, algorithm: CompressionAlgorithm) -> Result<(), String> {
+        let mut decompressed = Vec::new();
+        match algorithm {
+            CompressionAlgorithm::Zlib => {
+                let mut decoder = ZlibDecoder::new(self.buffer.as_slice());
+                decoder
+                    .read_to_end(&mut decompressed)
+                    .map_err(|e| e.to_string())?;
+            }
+            CompressionAlgorithm::Deflate => {
+                let mut decoder = DeflateDecoder::new(self.buffer.as_slice());
+                decoder
+                    .read_to_end(&mut decompressed)
+                    .map_err(|e| e.to_string())?;
+            }
+            CompressionAlgorithm::Lzma => {
+                lzma_rs::lzma_decompress(&mut self.buffer.as_slice(), &mut decompressed)
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+        self.buffer = decompressed;
+        self.position = 0;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -161,7 +531,7 @@ mod tests {
     fn test_put_and_get_int32() {
         let mut buffer = ByteBuffer::new(None);
         buffer.put_int32(12345);
-        buffer.position = 0; // Reset position for reading
+        buffer.reset_position();
 
         let value = buffer.get_int32().unwrap(); // Desembrulha o Result
         assert_eq!(value, 12345);
@@ -171,7 +541,7 @@ mod tests {
     fn test_put_and_get_uint32() {
         let mut buffer = ByteBuffer::new(None);
         buffer.put_uint32(98765);
-        buffer.position = 0;
+        buffer.reset_position();
 
         let value = buffer.get_uint32().unwrap(); // Desembrulha o Result
         assert_eq!(value, 98765);
@@ -182,7 +552,7 @@ mod tests {
         let mut buffer = ByteBuffer::new(None);
         buffer.put_bool(true);
         buffer.put_bool(false);
-        buffer.position = 0;
+        buffer.reset_position();
 
         let value1 = buffer.get_bool().unwrap();
         let value2 = buffer.get_bool().unwrap();
@@ -195,7 +565,7 @@ mod tests {
         let mut buffer = ByteBuffer::new(None);
         let test_string = String::from("Hello, Rust!");
         buffer.put_string(&test_string);
-        buffer.position = 0;
+        buffer.reset_position();
 
         let value = buffer.get_string().unwrap();
         assert_eq!(value, test_string);
@@ -206,7 +576,7 @@ mod tests {
         let mut buffer = ByteBuffer::new(None);
         let vector = (1.0, 2.0, 3.0);
         buffer.put_vector(vector);
-        buffer.position = 0;
+        buffer.reset_position();
 
         let value = buffer.get_vector().unwrap();
         assert_eq!(value, vector);
@@ -217,12 +587,255 @@ mod tests {
         let mut buffer = ByteBuffer::new(None);
         let rotator = (45.0, 90.0, 180.0);
         buffer.put_rotator(rotator);
-        buffer.position = 0;
+        buffer.reset_position();
 
         let value = buffer.get_rotator().unwrap();
         assert_eq!(value, rotator);
     }
 
+    #[test]
+    fn test_put_and_get_integer_family() {
+        let mut buffer = ByteBuffer::new(None);
+        buffer.put_i8(-12);
+        buffer.put_u16(500);
+        buffer.put_i16(-500);
+        buffer.put_i64(-1234567890123);
+        buffer.put_u64(1234567890123);
+        buffer.put_f64(3.5);
+        buffer.reset_position();
+
+        assert_eq!(buffer.get_i8().unwrap(), -12);
+        assert_eq!(buffer.get_u16().unwrap(), 500);
+        assert_eq!(buffer.get_i16().unwrap(), -500);
+        assert_eq!(buffer.get_i64().unwrap(), -1234567890123);
+        assert_eq!(buffer.get_u64().unwrap(), 1234567890123);
+        assert_eq!(buffer.get_f64().unwrap(), 3.5);
+    }
+
+    #[test]
+    fn test_big_endian_round_trip() {
+        let mut buffer = ByteBuffer::new(None);
+        buffer.set_endian(Endian::Big);
+        buffer.put_int32(12345);
+        buffer.put_u16(500);
+        buffer.reset_position();
+
+        assert_eq!(buffer.get_int32().unwrap(), 12345);
+        assert_eq!(buffer.get_u16().unwrap(), 500);
+    }
+
+    #[test]
+    fn test_put_and_get_varint_u64() {
+        let mut buffer = ByteBuffer::new(None);
+        buffer.put_varint_u64(0);
+        buffer.put_varint_u64(127);
+        buffer.put_varint_u64(128);
+        buffer.put_varint_u64(u64::MAX);
+        buffer.reset_position();
+
+        assert_eq!(buffer.get_varint_u64().unwrap(), 0);
+        assert_eq!(buffer.get_varint_u64().unwrap(), 127);
+        assert_eq!(buffer.get_varint_u64().unwrap(), 128);
+        assert_eq!(buffer.get_varint_u64().unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn test_put_and_get_varint_i64() {
+        let mut buffer = ByteBuffer::new(None);
+        buffer.put_varint_i64(0);
+        buffer.put_varint_i64(-1);
+        buffer.put_varint_i64(i64::MIN);
+        buffer.put_varint_i64(i64::MAX);
+        buffer.reset_position();
+
+        assert_eq!(buffer.get_varint_i64().unwrap(), 0);
+        assert_eq!(buffer.get_varint_i64().unwrap(), -1);
+        assert_eq!(buffer.get_varint_i64().unwrap(), i64::MIN);
+        assert_eq!(buffer.get_varint_i64().unwrap(), i64::MAX);
+    }
+
+    #[test]
+    fn test_varint_rejects_truncated_stream() {
+        let mut buffer = ByteBuffer::new(None);
+        buffer.put_byte(0x80);
+        buffer.reset_position();
+
+        assert_eq!(buffer.get_varint_u64(), Err("Buffer underflow".to_string()));
+    }
+
+    #[test]
+    fn test_varint_rejects_stream_exceeding_ten_bytes() {
+        let mut buffer = ByteBuffer::new(None);
+        for _ in 0..11 {
+            buffer.put_byte(0x80);
+        }
+        buffer.reset_position();
+
+        assert_eq!(buffer.get_varint_u64(), Err("Varint overflow".to_string()));
+    }
+
+    #[test]
+    fn test_compress_and_uncompress_zlib() {
+        let mut buffer = ByteBuffer::new(None);
+        let test_string = String::from("Hello, Rust! Hello, Rust! Hello, Rust!");
+        buffer.put_string(&test_string);
+
+        buffer.compress(CompressionAlgorithm::Zlib).unwrap();
+        buffer.uncompress(CompressionAlgorithm::Zlib).unwrap();
+
+        let value = buffer.get_string().unwrap();
+        assert_eq!(value, test_string);
+    }
+
+    #[test]
+    fn test_compress_and_uncompress_deflate() {
+        let mut buffer = ByteBuffer::new(None);
+        let test_string = String::from("Hello, Rust! Hello, Rust! Hello, Rust!");
+        buffer.put_string(&test_string);
+
+        buffer.compress(CompressionAlgorithm::Deflate).unwrap();
+        buffer.uncompress(CompressionAlgorithm::Deflate).unwrap();
+
+        let value = buffer.get_string().unwrap();
+        assert_eq!(value, test_string);
+    }
+
+    #[test]
+    fn test_compress_and_uncompress_lzma() {
+        let mut buffer = ByteBuffer::new(None);
+        let test_string = String::from("Hello, Rust! Hello, Rust! Hello, Rust!");
+        buffer.put_string(&test_string);
+
+        buffer.compress(CompressionAlgorithm::Lzma).unwrap();
+        buffer.uncompress(CompressionAlgorithm::Lzma).unwrap();
+
+        let value = buffer.get_string().unwrap();
+        assert_eq!(value, test_string);
+    }
+
+    #[test]
+    fn test_len_and_is_empty() {
+        let mut buffer = ByteBuffer::new(None);
+        assert!(buffer.is_empty());
+
+        buffer.put_int32(12345);
+        assert_eq!(buffer.len(), 4);
+        assert!(!buffer.is_empty());
+    }
+
+    #[test]
+    fn test_set_position_bounds() {
+        let mut buffer = ByteBuffer::new(None);
+        buffer.put_int32(12345);
+
+        assert!(buffer.set_position(4).is_ok());
+        assert_eq!(buffer.position(), 4);
+        assert!(buffer.set_position(5).is_err());
+    }
+
+    #[test]
+    fn test_seek_start_current_end() {
+        let mut buffer = ByteBuffer::new(None);
+        buffer.put_int32(1);
+        buffer.put_int32(2);
+        buffer.put_int32(3);
+
+        assert_eq!(buffer.seek(SeekFrom::Start(4)).unwrap(), 4);
+        assert_eq!(buffer.get_int32().unwrap(), 2);
+
+        assert_eq!(buffer.seek(SeekFrom::Current(-4)).unwrap(), 4);
+        assert_eq!(buffer.get_int32().unwrap(), 2);
+
+        assert_eq!(buffer.seek(SeekFrom::End(-4)).unwrap(), 8);
+        assert_eq!(buffer.get_int32().unwrap(), 3);
+        assert_eq!(buffer.remaining(), 0);
+
+        assert!(buffer.seek(SeekFrom::Current(-100)).is_err());
+        assert_eq!(buffer.seek(SeekFrom::End(100)).unwrap(), buffer.len());
+    }
+
+    #[test]
+    fn test_peek_byte_and_int32() {
+        let mut buffer = ByteBuffer::new(None);
+        buffer.put_int32(12345);
+        buffer.reset_position();
+
+        assert_eq!(buffer.peek_int32().unwrap(), 12345);
+        assert_eq!(buffer.position(), 0);
+
+        assert_eq!(buffer.peek_byte().unwrap(), 0x39);
+        assert_eq!(buffer.position(), 0);
+
+        assert_eq!(buffer.get_int32().unwrap(), 12345);
+    }
+
+    #[test]
+    fn test_is_eof() {
+        let mut buffer = ByteBuffer::new(None);
+        buffer.put_byte(1);
+        buffer.reset_position();
+
+        assert!(!buffer.is_eof());
+        buffer.get_byte().unwrap();
+        assert!(buffer.is_eof());
+    }
+
+    #[test]
+    fn test_put_and_get_bytes() {
+        let mut buffer = ByteBuffer::new(None);
+        buffer.put_bytes(&[1, 2, 3, 4]);
+        buffer.reset_position();
+
+        let value = buffer.get_bytes(4).unwrap();
+        assert_eq!(value, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_get_slice() {
+        let mut buffer = ByteBuffer::new(None);
+        buffer.put_bytes(&[1, 2, 3, 4]);
+        buffer.reset_position();
+
+        let value = buffer.get_slice(4).unwrap();
+        assert_eq!(value, &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_get_slice_rejects_oversized_len_without_panicking() {
+        let mut buffer = ByteBuffer::new(None);
+        buffer.put_bytes(&[1, 2, 3, 4]);
+        buffer.set_position(1).unwrap();
+
+        assert_eq!(
+            buffer.get_slice(usize::MAX),
+            Err("Buffer underflow".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_bytes_rejects_oversized_len_without_panicking() {
+        let mut buffer = ByteBuffer::new(None);
+        buffer.put_bytes(&[1, 2, 3, 4]);
+        buffer.set_position(1).unwrap();
+
+        assert_eq!(
+            buffer.get_bytes(usize::MAX),
+            Err("Buffer underflow".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_string_rejects_negative_length_prefix() {
+        let mut buffer = ByteBuffer::new(None);
+        buffer.put_bytes(&[0xFF, 0xFF, 0xFF, 0xFF]);
+        buffer.reset_position();
+
+        assert_eq!(
+            buffer.get_string(),
+            Err("Buffer underflow".to_string())
+        );
+    }
+
     #[test]
     fn test_to_hex() {
         let mut buffer = ByteBuffer::new(None);